@@ -25,11 +25,12 @@ pub use anstyle_lossy::palette::WIN10_CONSOLE;
 #[derive(Copy, Clone, Debug)]
 pub struct Term {
     palette: Palette,
-    fg_color: anstyle::Color,
-    bg_color: anstyle::Color,
-    background: bool,
+    fg_color: Option<anstyle::Color>,
+    bg_color: Option<anstyle::Color>,
+    bold_is_bright: bool,
     font_family: &'static str,
     min_width_px: usize,
+    line_numbers: bool,
 }
 
 impl Term {
@@ -37,11 +38,12 @@ impl Term {
     pub const fn new() -> Self {
         Self {
             palette: WIN10_CONSOLE,
-            fg_color: FG_COLOR,
-            bg_color: BG_COLOR,
-            background: true,
+            fg_color: Some(FG_COLOR),
+            bg_color: Some(BG_COLOR),
+            bold_is_bright: false,
             font_family: "SFMono-Regular, Consolas, Liberation Mono, Menlo, monospace",
             min_width_px: 720,
+            line_numbers: false,
         }
     }
 
@@ -52,20 +54,29 @@ impl Term {
     }
 
     /// Select the default foreground color
-    pub const fn fg_color(mut self, color: anstyle::Color) -> Self {
+    ///
+    /// Pass `None` to leave unstyled text unset so it inherits the
+    /// embedding page's own color instead of being forced to a fixed value.
+    pub const fn fg_color(mut self, color: Option<anstyle::Color>) -> Self {
         self.fg_color = color;
         self
     }
 
     /// Select the default background color
-    pub const fn bg_color(mut self, color: anstyle::Color) -> Self {
+    ///
+    /// Pass `None` to leave unstyled text unset so it inherits the
+    /// embedding page's own background instead of being forced to a fixed value.
+    pub const fn bg_color(mut self, color: Option<anstyle::Color>) -> Self {
         self.bg_color = color;
         self
     }
 
     /// Toggle default background off with `false`
+    ///
+    /// Shorthand for [`Term::bg_color`]`(None)`; use `bg_color` directly to pick a specific
+    /// default background color.
     pub const fn background(mut self, yes: bool) -> Self {
-        self.background = yes;
+        self.bg_color = if yes { Some(BG_COLOR) } else { None };
         self
     }
 
@@ -75,34 +86,30 @@ impl Term {
         self
     }
 
+    /// Render a bold foreground in one of the standard 8 ANSI colors as its bright
+    /// counterpart, matching how many terminals render bold text
+    ///
+    /// Off by default to preserve the previous output.
+    pub const fn bold_is_bright(mut self, yes: bool) -> Self {
+        self.bold_is_bright = yes;
+        self
+    }
+
+    /// Prepend a non-selectable line-number gutter before each rendered line
+    pub const fn line_numbers(mut self, yes: bool) -> Self {
+        self.line_numbers = yes;
+        self
+    }
+
     /// Render the HTML with the terminal defined
+    ///
+    /// This produces a complete, standalone document with its styles inlined. To embed the
+    /// output in an existing page, or to share one stylesheet across several snippets, use
+    /// [`Term::render_fragment`] and [`Term::render_stylesheet`] instead.
     pub fn render_html(&self, ansi: &str) -> String {
         use std::fmt::Write as _;
 
-        const FG: &str = "fg";
-        const BG: &str = "bg";
-
-        let mut styled = adapter::AnsiBytes::new();
-        let mut elements = styled.extract_next(ansi.as_bytes()).collect::<Vec<_>>();
-        let mut effects_in_use = anstyle::Effects::new();
-        for element in &mut elements {
-            let style = &mut element.style;
-            // Pre-process INVERT to make fg/bg calculations easier
-            if style.get_effects().contains(anstyle::Effects::INVERT) {
-                *style = style
-                    .fg_color(Some(style.get_bg_color().unwrap_or(self.bg_color)))
-                    .bg_color(Some(style.get_fg_color().unwrap_or(self.fg_color)))
-                    .effects(style.get_effects().remove(anstyle::Effects::INVERT));
-            }
-            effects_in_use |= style.get_effects();
-        }
-        let styled_lines = split_lines(&elements);
-
-        let fg_color = rgb_value(self.fg_color, self.palette);
-        let bg_color = rgb_value(self.bg_color, self.palette);
-        let font_family = self.font_family;
-
-        let line_height = 18;
+        let analysis = self.analyze(ansi);
 
         let mut buffer = String::new();
         writeln!(&mut buffer, r#"<!DOCTYPE html>"#).unwrap();
@@ -120,9 +127,113 @@ impl Term {
         )
         .unwrap();
         writeln!(&mut buffer, r#"  <style>"#).unwrap();
-        writeln!(&mut buffer, r#"    .{FG} {{ color: {fg_color} }}"#).unwrap();
-        writeln!(&mut buffer, r#"    .{BG} {{ background: {bg_color} }}"#).unwrap();
-        for (name, rgb) in color_styles(&elements, self.palette) {
+        write!(&mut buffer, "{}", self.render_stylesheet_with(&analysis)).unwrap();
+        writeln!(&mut buffer, r#"  </style>"#).unwrap();
+        writeln!(&mut buffer, r#"</head>"#).unwrap();
+        writeln!(&mut buffer).unwrap();
+
+        if analysis.use_bg {
+            writeln!(&mut buffer, r#"<body class="{BG}">"#).unwrap();
+        } else {
+            writeln!(&mut buffer, r#"<body>"#).unwrap();
+        }
+        writeln!(&mut buffer).unwrap();
+
+        write!(&mut buffer, "{}", self.render_fragment_with(&analysis)).unwrap();
+        writeln!(&mut buffer).unwrap();
+
+        writeln!(&mut buffer, r#"</body>"#).unwrap();
+        writeln!(&mut buffer, r#"</html>"#).unwrap();
+        buffer
+    }
+
+    /// Render just the `<div class="container">…</div>` fragment: the styled spans, `<br/>`s
+    /// and background-fill rows, without the surrounding `<!DOCTYPE html>`/`<head>`/`<style>`.
+    ///
+    /// Pair with [`Term::render_stylesheet`] (or a shared, deduplicated stylesheet built from
+    /// several calls) when embedding the output into an existing page, e.g. for server-side
+    /// templating or static-site generation.
+    pub fn render_fragment(&self, ansi: &str) -> String {
+        let analysis = self.analyze(ansi);
+        self.render_fragment_with(&analysis)
+    }
+
+    fn render_fragment_with(&self, analysis: &Analysis) -> String {
+        use std::fmt::Write as _;
+
+        let mut buffer = String::new();
+        let mut classes = vec!["container"];
+        if analysis.use_fg {
+            classes.push(FG);
+        }
+        if analysis.use_bg {
+            classes.push(BG);
+        }
+        let classes = classes.join(" ");
+        writeln!(&mut buffer, r#"  <div class="{classes}">"#).unwrap();
+        for (index, line) in analysis.styled_lines.iter().enumerate() {
+            if line.iter().any(|e| e.style.get_bg_color().is_some()) {
+                if self.line_numbers {
+                    // Reserve the gutter's width on the fill row too, without a number,
+                    // so it lines up under the text row's own gutter.
+                    write!(&mut buffer, r#"<span class="line-number"></span>"#).unwrap();
+                }
+                for element in line {
+                    if element.text.is_empty() {
+                        continue;
+                    }
+                    write_bg_span(&mut buffer, &element.style, &element.text);
+                }
+                writeln!(&mut buffer, r#"<br />"#).unwrap();
+            }
+
+            if self.line_numbers {
+                write!(
+                    &mut buffer,
+                    r#"<span class="line-number">{}</span>"#,
+                    index + 1
+                )
+                .unwrap();
+            }
+            for element in line {
+                if element.text.is_empty() {
+                    continue;
+                }
+                write_fg_span(&mut buffer, element, &element.text);
+            }
+            writeln!(&mut buffer, r#"<br />"#).unwrap();
+        }
+        writeln!(&mut buffer, r#"  </div>"#).unwrap();
+        buffer
+    }
+
+    /// Render just the CSS rules referenced by [`Term::render_fragment`]'s output, without the
+    /// surrounding `<style>` tags.
+    ///
+    /// Callers embedding several rendered snippets in one page can call this once per distinct
+    /// [`Term`]/ANSI-input pair and deduplicate the resulting rules into a single shared
+    /// stylesheet.
+    pub fn render_stylesheet(&self, ansi: &str) -> String {
+        let analysis = self.analyze(ansi);
+        self.render_stylesheet_with(&analysis)
+    }
+
+    fn render_stylesheet_with(&self, analysis: &Analysis) -> String {
+        use std::fmt::Write as _;
+
+        let line_height = 18;
+        let font_family = self.font_family;
+
+        let mut buffer = String::new();
+        if analysis.use_fg {
+            let fg_color = analysis.fg_color.as_deref().unwrap();
+            writeln!(&mut buffer, r#"    .{FG} {{ color: {fg_color} }}"#).unwrap();
+        }
+        if analysis.use_bg {
+            let bg_color = analysis.bg_color.as_deref().unwrap();
+            writeln!(&mut buffer, r#"    .{BG} {{ background: {bg_color} }}"#).unwrap();
+        }
+        for (name, rgb) in color_styles(&analysis.elements, self.palette) {
             if name.starts_with(FG_PREFIX) {
                 writeln!(&mut buffer, r#"    .{name} {{ color: {rgb} }}"#).unwrap();
             }
@@ -141,61 +252,76 @@ impl Term {
                 .unwrap();
             }
         }
+        let min_width_px = self.min_width_px;
         writeln!(&mut buffer, r#"    .container {{"#).unwrap();
         writeln!(&mut buffer, r#"      line-height: {line_height}px;"#).unwrap();
+        writeln!(&mut buffer, r#"      min-width: {min_width_px}px;"#).unwrap();
         writeln!(&mut buffer, r#"    }}"#).unwrap();
-        if effects_in_use.contains(anstyle::Effects::BOLD) {
+        if self.line_numbers {
+            let digits = analysis.styled_lines.len().max(1).to_string().len();
+            writeln!(&mut buffer, r#"    .line-number {{"#).unwrap();
+            writeln!(&mut buffer, r#"      display: inline-block;"#).unwrap();
+            writeln!(&mut buffer, r#"      width: {digits}ch;"#).unwrap();
+            writeln!(&mut buffer, r#"      padding-right: 1ch;"#).unwrap();
+            writeln!(&mut buffer, r#"      text-align: right;"#).unwrap();
+            writeln!(&mut buffer, r#"      user-select: none;"#).unwrap();
+            writeln!(&mut buffer, r#"    }}"#).unwrap();
+        }
+        if analysis.effects_in_use.contains(anstyle::Effects::BOLD) {
             writeln!(&mut buffer, r#"    .bold {{ font-weight: bold; }}"#).unwrap();
         }
-        if effects_in_use.contains(anstyle::Effects::ITALIC) {
+        if analysis.effects_in_use.contains(anstyle::Effects::ITALIC) {
             writeln!(&mut buffer, r#"    .italic {{ font-style: italic; }}"#).unwrap();
         }
-        if effects_in_use.contains(anstyle::Effects::UNDERLINE) {
+        if analysis.underline_styles_in_use.contains("underline") {
             writeln!(
                 &mut buffer,
                 r#"    .underline {{ text-decoration-line: underline; }}"#
             )
             .unwrap();
         }
-        if effects_in_use.contains(anstyle::Effects::DOUBLE_UNDERLINE) {
+        if analysis.underline_styles_in_use.contains("double-underline") {
             writeln!(
                 &mut buffer,
                 r#"    .double-underline {{ text-decoration-line: underline; text-decoration-style: double; }}"#
             )
             .unwrap();
         }
-        if effects_in_use.contains(anstyle::Effects::CURLY_UNDERLINE) {
+        if analysis.underline_styles_in_use.contains("curly-underline") {
             writeln!(
                 &mut buffer,
                 r#"    .curly-underline {{ text-decoration-line: underline; text-decoration-style: wavy; }}"#
             )
             .unwrap();
         }
-        if effects_in_use.contains(anstyle::Effects::DOTTED_UNDERLINE) {
+        if analysis.underline_styles_in_use.contains("dotted-underline") {
             writeln!(
                 &mut buffer,
                 r#"    .dotted-underline {{ text-decoration-line: underline; text-decoration-style: dotted; }}"#
             )
             .unwrap();
         }
-        if effects_in_use.contains(anstyle::Effects::DASHED_UNDERLINE) {
+        if analysis.underline_styles_in_use.contains("dashed-underline") {
             writeln!(
                 &mut buffer,
                 r#"    .dashed-underline {{ text-decoration-line: underline; text-decoration-style: dashed; }}"#
             )
             .unwrap();
         }
-        if effects_in_use.contains(anstyle::Effects::STRIKETHROUGH) {
+        if analysis
+            .effects_in_use
+            .contains(anstyle::Effects::STRIKETHROUGH)
+        {
             writeln!(
                 &mut buffer,
                 r#"    .strikethrough {{ text-decoration-line: line-through; }}"#
             )
             .unwrap();
         }
-        if effects_in_use.contains(anstyle::Effects::DIMMED) {
+        if analysis.effects_in_use.contains(anstyle::Effects::DIMMED) {
             writeln!(&mut buffer, r#"    .dimmed {{ opacity: 0.7; }}"#).unwrap();
         }
-        if effects_in_use.contains(anstyle::Effects::HIDDEN) {
+        if analysis.effects_in_use.contains(anstyle::Effects::HIDDEN) {
             writeln!(&mut buffer, r#"    .hidden {{ opacity: 0; }}"#).unwrap();
         }
         writeln!(&mut buffer, r#"    span {{"#).unwrap();
@@ -203,49 +329,96 @@ impl Term {
         writeln!(&mut buffer, r#"      white-space: pre;"#).unwrap();
         writeln!(&mut buffer, r#"      line-height: {line_height}px;"#).unwrap();
         writeln!(&mut buffer, r#"    }}"#).unwrap();
-        writeln!(&mut buffer, r#"  </style>"#).unwrap();
-        writeln!(&mut buffer, r#"</head>"#).unwrap();
-        writeln!(&mut buffer).unwrap();
-
-        if !self.background {
-            writeln!(&mut buffer, r#"<body>"#).unwrap();
-        } else {
-            writeln!(&mut buffer, r#"<body class="{BG}">"#).unwrap();
-        }
-        writeln!(&mut buffer).unwrap();
+        buffer
+    }
 
-        writeln!(&mut buffer, r#"  <div class="container {FG}">"#).unwrap();
-        for line in &styled_lines {
-            if line.iter().any(|e| e.style.get_bg_color().is_some()) {
-                for element in line {
-                    if element.text.is_empty() {
-                        continue;
-                    }
-                    write_bg_span(&mut buffer, &element.style, &element.text);
-                }
-                writeln!(&mut buffer, r#"<br />"#).unwrap();
+    /// Parse `ansi` and compute everything [`Term::render_fragment`] and
+    /// [`Term::render_stylesheet`] need, so the two agree on what the rendered output contains.
+    fn analyze(&self, ansi: &str) -> Analysis {
+        let mut styled = adapter::AnsiBytes::new();
+        let mut elements = styled.extract_next(ansi.as_bytes()).collect::<Vec<_>>();
+        let mut effects_in_use = anstyle::Effects::new();
+        let mut underline_styles_in_use = std::collections::BTreeSet::new();
+        for element in &mut elements {
+            let style = &mut element.style;
+            // Pre-process INVERT to make fg/bg calculations easier
+            if style.get_effects().contains(anstyle::Effects::INVERT) {
+                let fg = style.get_bg_color().or(self.bg_color);
+                let bg = style.get_fg_color().or(self.fg_color);
+                *style = style
+                    .fg_color(fg)
+                    .bg_color(bg)
+                    .effects(style.get_effects().remove(anstyle::Effects::INVERT));
             }
-
-            for element in line {
-                if element.text.is_empty() {
-                    continue;
+            // Brighten a bold foreground in the standard 8-color range, matching how many
+            // terminals render bold text.
+            if self.bold_is_bright && style.get_effects().contains(anstyle::Effects::BOLD) {
+                if let Some(anstyle::Color::Ansi(color)) = style.get_fg_color() {
+                    *style = style.fg_color(Some(anstyle::Color::Ansi(brighten(color))));
                 }
-                write_fg_span(&mut buffer, element, &element.text);
             }
-            writeln!(&mut buffer, r#"<br />"#).unwrap();
+            effects_in_use |= style.get_effects();
+            if let Some(name) = underline_style_class(style) {
+                underline_styles_in_use.insert(name);
+            }
         }
-        writeln!(&mut buffer, r#"  </div>"#).unwrap();
-        writeln!(&mut buffer).unwrap();
+        let styled_lines = split_lines(&elements);
 
-        writeln!(&mut buffer, r#"</body>"#).unwrap();
-        writeln!(&mut buffer, r#"</html>"#).unwrap();
-        buffer
+        // Only emit the default `.fg`/`.bg` rule (and apply it to the container/body) when a
+        // default color is actually configured; when it's `None`, unstyled text is left alone
+        // so the embedding document's own colors show through.
+        let use_fg = self.fg_color.is_some();
+        let use_bg = self.bg_color.is_some();
+        let fg_color = self.fg_color.map(|c| rgb_value(c, self.palette));
+        let bg_color = self.bg_color.map(|c| rgb_value(c, self.palette));
+
+        Analysis {
+            elements,
+            styled_lines,
+            effects_in_use,
+            underline_styles_in_use,
+            use_fg,
+            use_bg,
+            fg_color,
+            bg_color,
+        }
     }
 }
 
+/// Intermediate result shared by [`Term::render_fragment`] and [`Term::render_stylesheet`].
+struct Analysis {
+    elements: Vec<adapter::Element>,
+    styled_lines: Vec<Vec<adapter::Element>>,
+    effects_in_use: anstyle::Effects,
+    underline_styles_in_use: std::collections::BTreeSet<&'static str>,
+    use_fg: bool,
+    use_bg: bool,
+    fg_color: Option<String>,
+    bg_color: Option<String>,
+}
+
+const FG: &str = "fg";
+const BG: &str = "bg";
+
 const FG_COLOR: anstyle::Color = anstyle::Color::Ansi(anstyle::AnsiColor::White);
 const BG_COLOR: anstyle::Color = anstyle::Color::Ansi(anstyle::AnsiColor::Black);
 
+/// Map a standard (0-7) ANSI color to its bright (8-15) counterpart, leaving an
+/// already-bright color untouched.
+fn brighten(color: anstyle::AnsiColor) -> anstyle::AnsiColor {
+    match color {
+        anstyle::AnsiColor::Black => anstyle::AnsiColor::BrightBlack,
+        anstyle::AnsiColor::Red => anstyle::AnsiColor::BrightRed,
+        anstyle::AnsiColor::Green => anstyle::AnsiColor::BrightGreen,
+        anstyle::AnsiColor::Yellow => anstyle::AnsiColor::BrightYellow,
+        anstyle::AnsiColor::Blue => anstyle::AnsiColor::BrightBlue,
+        anstyle::AnsiColor::Magenta => anstyle::AnsiColor::BrightMagenta,
+        anstyle::AnsiColor::Cyan => anstyle::AnsiColor::BrightCyan,
+        anstyle::AnsiColor::White => anstyle::AnsiColor::BrightWhite,
+        bright => bright,
+    }
+}
+
 fn write_fg_span(buffer: &mut String, element: &adapter::Element, fragment: &str) {
     use std::fmt::Write as _;
     let style = element.style;
@@ -254,11 +427,7 @@ fn write_fg_span(buffer: &mut String, element: &adapter::Element, fragment: &str
         .get_underline_color()
         .map(|c| color_name(UNDERLINE_PREFIX, c));
     let effects = style.get_effects();
-    let underline = effects.contains(anstyle::Effects::UNDERLINE);
-    let double_underline = effects.contains(anstyle::Effects::DOUBLE_UNDERLINE);
-    let curly_underline = effects.contains(anstyle::Effects::CURLY_UNDERLINE);
-    let dotted_underline = effects.contains(anstyle::Effects::DOTTED_UNDERLINE);
-    let dashed_underline = effects.contains(anstyle::Effects::DASHED_UNDERLINE);
+    let underline_style = underline_style_class(&style);
     let strikethrough = effects.contains(anstyle::Effects::STRIKETHROUGH);
     // skipping INVERT as that was handled earlier
     let bold = effects.contains(anstyle::Effects::BOLD);
@@ -274,20 +443,8 @@ fn write_fg_span(buffer: &mut String, element: &adapter::Element, fragment: &str
     if let Some(class) = underline_color.as_deref() {
         classes.push(class);
     }
-    if underline {
-        classes.push("underline");
-    }
-    if double_underline {
-        classes.push("double-underline");
-    }
-    if curly_underline {
-        classes.push("curly-underline");
-    }
-    if dotted_underline {
-        classes.push("dotted-underline");
-    }
-    if dashed_underline {
-        classes.push("dashed-underline");
+    if let Some(class) = underline_style {
+        classes.push(class);
     }
     if strikethrough {
         classes.push("strikethrough");
@@ -384,6 +541,30 @@ const FG_PREFIX: &str = "fg";
 const BG_PREFIX: &str = "bg";
 const UNDERLINE_PREFIX: &str = "underline";
 
+/// Pick the single underline style a `Style` should render as.
+///
+/// The underline-style effects are mutually exclusive in real terminals (a later style
+/// replaces an earlier one), so only the highest-precedence bit present is honored:
+/// curly > dashed > dotted > double > single. An underline color is a first-class field
+/// independent of the effect flags, so it alone is enough to request a (plain) underline.
+fn underline_style_class(style: &anstyle::Style) -> Option<&'static str> {
+    let effects = style.get_effects();
+    if effects.contains(anstyle::Effects::CURLY_UNDERLINE) {
+        Some("curly-underline")
+    } else if effects.contains(anstyle::Effects::DASHED_UNDERLINE) {
+        Some("dashed-underline")
+    } else if effects.contains(anstyle::Effects::DOTTED_UNDERLINE) {
+        Some("dotted-underline")
+    } else if effects.contains(anstyle::Effects::DOUBLE_UNDERLINE) {
+        Some("double-underline")
+    } else if effects.contains(anstyle::Effects::UNDERLINE) || style.get_underline_color().is_some()
+    {
+        Some("underline")
+    } else {
+        None
+    }
+}
+
 fn color_name(prefix: &str, color: anstyle::Color) -> String {
     match color {
         anstyle::Color::Ansi(color) => {